@@ -0,0 +1,14 @@
+pub(crate) mod model;
+
+use crate::{auth::AuthContext, db::DbConnection};
+
+
+/// Data available to every resolver.
+pub(crate) struct Context {
+    pub(crate) db: DbConnection,
+    pub(crate) auth: AuthContext,
+}
+
+impl juniper::Context for Context {}
+
+pub(crate) struct Query;
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use juniper::graphql_object;
+
+use crate::search::update::{self, IndexTaskRecord};
+
+use super::super::{Context, Query};
+
+/// Upper bound on `Query::search_index_tasks`'s `limit` argument, so a
+/// caller can't force an unbounded scan/allocation.
+const MAX_INDEX_TASKS_LIMIT: i32 = 100;
+
+
+/// One row of `search_index_tasks`, as returned by `searchIndexTasks`.
+pub(crate) struct IndexTask(IndexTaskRecord);
+
+impl From<IndexTaskRecord> for IndexTask {
+    fn from(record: IndexTaskRecord) -> Self {
+        Self(record)
+    }
+}
+
+#[graphql_object(context = Context)]
+impl IndexTask {
+    fn id(&self) -> juniper::ID {
+        juniper::ID::new(self.0.id.to_string())
+    }
+
+    fn kind(&self) -> &str {
+        &self.0.kind
+    }
+
+    fn started_at(&self) -> DateTime<Utc> {
+        self.0.started_at
+    }
+
+    fn finished_at(&self) -> DateTime<Utc> {
+        self.0.finished_at
+    }
+
+    fn db_load_duration_ms(&self) -> f64 {
+        self.0.db_load_duration_ms as f64
+    }
+
+    fn meili_duration_ms(&self) -> f64 {
+        self.0.meili_duration_ms as f64
+    }
+
+    fn added_count(&self) -> f64 {
+        self.0.added_count as f64
+    }
+
+    fn deleted_count(&self) -> f64 {
+        self.0.deleted_count as f64
+    }
+
+    fn error(&self) -> &Option<String> {
+        &self.0.error
+    }
+}
+
+#[graphql_object(context = Context)]
+impl Query {
+    /// The most recent entries from the search index task history, newest
+    /// first. Moderator-only, like other operational data.
+    async fn search_index_tasks(context: &Context, limit: i32) -> juniper::FieldResult<Vec<IndexTask>> {
+        if !context.auth.is_moderator() {
+            return Err("not authorized to view search index task history".into());
+        }
+
+        let limit = limit.clamp(0, MAX_INDEX_TASKS_LIMIT);
+        let mut db = context.db.clone();
+        let records = update::recent_tasks(&mut db, limit as i64).await?;
+        Ok(records.into_iter().map(IndexTask::from).collect())
+    }
+}
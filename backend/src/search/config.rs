@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use confique::Config;
+
+
+/// Configuration for the connection to Meilisearch and for the
+/// `update_index` background task that keeps it in sync with the DB.
+#[derive(Debug, Confique)]
+pub(crate) struct MeiliConfig {
+    /// How often the `update_index` background task wakes up to check
+    /// `search_index_queue` for new items.
+    #[config(default = "5s")]
+    pub(crate) update_interval: Duration,
+
+    /// After noticing a non-empty `search_index_queue`, how long
+    /// `update_index` waits before sending anything to Meili. This
+    /// coalesces a burst of writes (e.g. a large Opencast sync) into fewer,
+    /// larger batches instead of many tiny ones.
+    #[config(default = "2s")]
+    pub(crate) debounce_duration: Duration,
+
+    /// The maximum number of `search_index_queue` rows loaded in a single
+    /// batch. Of these, at most `max_documents_per_batch` are sent to Meili
+    /// per batch, so raising this above that limit does not reduce the number
+    /// of batches needed to drain a large queue.
+    #[config(default = 10_000)]
+    pub(crate) max_batch_size: u32,
+
+    /// The maximum number of documents actually sent to Meili in a single
+    /// batch. Rows beyond this limit are left in `search_index_queue` and
+    /// picked up by the next batch, so a single oversized sync doesn't
+    /// force one huge `add_documents` call. Documents are loaded from the DB
+    /// only for the rows that are sent, so this also bounds how many
+    /// documents a batch holds in memory.
+    #[config(default = 1_000)]
+    pub(crate) max_documents_per_batch: u32,
+}
@@ -1,9 +1,13 @@
 use std::{
     collections::HashSet,
     future::Future,
-    time::Instant,
+    time::{Duration, Instant},
 };
 
+use chrono::{DateTime, Utc};
+use deadpool_postgres::GenericClient;
+use meilisearch_sdk::{task_info::TaskInfo, tasks::Task};
+
 use crate::{
     db::{DbConnection, types::Key, util::select},
     prelude::*,
@@ -16,6 +20,20 @@ use super::{
 };
 
 
+/// How long to wait between polls of a Meili task's status.
+const TASK_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Give up waiting for a task to reach a terminal state after this long. The
+/// task then counts as not succeeded: its items are retried individually, and
+/// any that still fail stay in the queue (instead of being deleted) with their
+/// retry counter bumped.
+const TASK_POLL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How many failed attempts (including the first) an item gets before it is
+/// given up on and moved to `search_index_dead_letter`.
+const MAX_RETRY_ATTEMPTS: i32 = 8;
+
+
 /// Calls `update_index` roughly every `config.update_interval` and never returns.
 pub(crate) async fn update_index_daemon(meili: &Client, db: &mut DbConnection) -> Result<Never> {
     loop {
@@ -31,78 +49,417 @@ pub(crate) async fn update_index_daemon(meili: &Client, db: &mut DbConnection) -
 
 /// Processes the "search index queue" in the DB by dequeuing some items and
 /// sending them to the search index. Stops once the queue is empty.
+///
+/// This auto-batches: once it notices a non-empty queue, it waits
+/// `config.debounce_duration` before sending anything, so a burst of writes
+/// (e.g. a large Opencast sync) gets coalesced into fewer, larger batches
+/// instead of many tiny ones. Each batch is capped at `config.max_batch_size`
+/// queue rows and `config.max_documents_per_batch` documents sent to Meili,
+/// while always including at least one item so a single oversized item can't
+/// stall the queue forever.
 pub(crate) async fn update_index(meili: &Client, db: &mut DbConnection) -> Result<()> {
-    const CHUNK_SIZE: u32 = 5000;
+    if queue_is_empty(db).await? {
+        trace!("No index update queued -> doing nothing");
+        return Ok(());
+    }
+
+    trace!("Search index queue is non-empty, debouncing for {:.1?}", meili.config.debounce_duration);
+    tokio::time::sleep(meili.config.debounce_duration).await;
 
     loop {
-        let done = writer::with_write_lock(db, meili, move |tx, meili| Box::pin(async move {
-            // First, we retrieve a list of items that need updating.
-            let (selection, mapping) = select!(item_id, kind);
-            let query = format!("select {selection} \
-                from search_index_queue \
-                order by id \
-                limit {CHUNK_SIZE}");
-
-            let row_stream = tx.query_raw(&query, dbargs![]).await
-                .context("failed to load IDs from search index queue")?;
-
-            let mut event_ids = Vec::new();
-            let mut realm_ids = Vec::new();
-            futures::pin_mut!(row_stream);
-            while let Some(row) = row_stream.try_next().await? {
-                let key: Key = mapping.item_id.of(&row);
-                let kind: IndexItemKind = mapping.kind.of(&row);
-                match kind {
-                    IndexItemKind::Realm => realm_ids.push(key),
-                    IndexItemKind::Event => event_ids.push(key),
+        let Some(EnqueuedBatch {
+            batch_started_at, realm_ids, event_ids, realm_outcome, event_outcome, queue_drained,
+        }) = enqueue_batch(db, meili).await? else { break };
+
+        // Wait for the enqueued tasks outside the write lock `enqueue_batch`
+        // took; on failure, retry the batch's items individually.
+        let meili_started_at = Instant::now();
+        let mut realm_failed = Vec::new();
+        let realm_ids = if meili.wait_for_tasks(realm_outcome.tasks).await {
+            realm_ids
+        } else {
+            warn!(
+                "Meili task(s) for {} realm(s) did not succeed, retrying them individually",
+                realm_ids.len(),
+            );
+            let mut confirmed = Vec::new();
+            for id in realm_ids {
+                let outcome = meili.update(&[id], || Realm::load_by_ids(&**db, &[id])).await
+                    .context("failed to send realm to search index individually")?;
+                if meili.wait_for_tasks(outcome.tasks).await {
+                    confirmed.push(id);
+                } else {
+                    realm_failed.push(id);
                 }
             }
+            confirmed
+        };
+        let realm_meili_duration = meili_started_at.elapsed();
 
-            let count = event_ids.len() + realm_ids.len();
-            if count == 0 {
-                trace!("No index update queued -> doing nothing");
-                return Ok(true);
+        let meili_started_at = Instant::now();
+        let mut event_failed = Vec::new();
+        let event_ids = if meili.wait_for_tasks(event_outcome.tasks).await {
+            event_ids
+        } else {
+            warn!(
+                "Meili task(s) for {} event(s) did not succeed, retrying them individually",
+                event_ids.len(),
+            );
+            let mut confirmed = Vec::new();
+            for id in event_ids {
+                let outcome = meili.update(&[id], || Event::load_by_ids(&**db, &[id])).await
+                    .context("failed to send event to search index individually")?;
+                if meili.wait_for_tasks(outcome.tasks).await {
+                    confirmed.push(id);
+                } else {
+                    event_failed.push(id);
+                }
             }
+            confirmed
+        };
+        let event_meili_duration = meili_started_at.elapsed();
+
+        let done = finalize_batch(db, meili, FinalizedBatch {
+            batch_started_at,
+            queue_drained,
+            realm_ids, realm_failed, realm_outcome, realm_meili_duration,
+            event_ids, event_failed, event_outcome, event_meili_duration,
+        }).await?;
+
+        if done {
+            break;
+        }
+    }
 
-            trace!("Loaded {} IDs from search index queue", count);
+    Ok(())
+}
 
+/// One page of `search_index_queue`, already enqueued with Meili.
+struct EnqueuedBatch {
+    batch_started_at: DateTime<Utc>,
+    realm_ids: Vec<Key>,
+    event_ids: Vec<Key>,
+    realm_outcome: UpdateOutcome,
+    event_outcome: UpdateOutcome,
+    queue_drained: bool,
+}
 
-            // Load items from DB and push them into the index.
-            meili.update(&realm_ids, || Realm::load_by_ids(&**tx, &realm_ids)).await
-                .context("failed to send realms to search index")?;
-            meili.update(&event_ids, || Event::load_by_ids(&**tx, &event_ids)).await
-                .context("failed to send events to search index")?;
+/// Selects the next page of `search_index_queue` and enqueues it with Meili,
+/// all inside one write-locked transaction. Returns `None` if there was
+/// nothing to do. Does not wait for the Meili tasks to complete; the caller
+/// does that after the transaction is released.
+async fn enqueue_batch(db: &mut DbConnection, meili: &Client) -> Result<Option<EnqueuedBatch>> {
+    writer::with_write_lock(db, meili, move |tx, meili| Box::pin(async move {
+        let batch_started_at = Utc::now();
 
-            // Delete all items that we have sent to the search index already.
-            let sql = "delete from search_index_queue \
-                where item_id = any($1) and kind = 'realm' \
-                or item_id = any($2) and kind = 'event'";
-            let affected = tx.execute(sql, &[&realm_ids, &event_ids]).await
-                .context("failed to remove items from search index queue")?;
-            debug!("Removed {affected} items from the search index queue");
+        let (selection, mapping) = select!(item_id, kind);
+        let query = format!("select {selection} \
+            from search_index_queue \
+            where next_attempt_at is null or next_attempt_at <= now() \
+            order by id \
+            limit {}", meili.config.max_batch_size);
 
-            if affected != count as u64 {
-                warn!("Wanted to delete {count} items from search index queue, \
-                    but deleted {affected}");
+        let row_stream = tx.query_raw(&query, dbargs![]).await
+            .context("failed to load IDs from search index queue")?;
+
+        let mut event_ids = Vec::new();
+        let mut realm_ids = Vec::new();
+        futures::pin_mut!(row_stream);
+        while let Some(row) = row_stream.try_next().await? {
+            let key: Key = mapping.item_id.of(&row);
+            let kind: IndexItemKind = mapping.kind.of(&row);
+            match kind {
+                IndexItemKind::Realm => realm_ids.push(key),
+                IndexItemKind::Event => event_ids.push(key),
             }
+        }
 
-            Ok(count < CHUNK_SIZE as usize)
-        })).await?;
+        let count = event_ids.len() + realm_ids.len();
+        if count == 0 {
+            return Ok(None);
+        }
 
+        // Cap the number of documents we actually send to Meili in this
+        // batch; any rows trimmed off here simply stay in the queue and
+        // get picked up by the next batch of `update_index`'s loop.
+        cap_to_document_budget(&mut realm_ids, &mut event_ids, meili.config.max_documents_per_batch);
+        let sent_count = realm_ids.len() + event_ids.len();
 
-        if done {
-            break;
+        // Drained only if this page wasn't full and nothing was trimmed.
+        let queue_drained = count < meili.config.max_batch_size as usize && sent_count == count;
+
+        trace!("Loaded {} IDs from search index queue", sent_count);
+
+        let realm_outcome = meili.update(&realm_ids, || Realm::load_by_ids(&**tx, &realm_ids)).await
+            .context("failed to send realms to search index")?;
+        let event_outcome = meili.update(&event_ids, || Event::load_by_ids(&**tx, &event_ids)).await
+            .context("failed to send events to search index")?;
+
+        Ok(Some(EnqueuedBatch {
+            batch_started_at, realm_ids, event_ids, realm_outcome, event_outcome, queue_drained,
+        }))
+    })).await
+}
+
+/// Everything needed to finalize one batch once Meili has confirmed (or
+/// given up on) its documents.
+struct FinalizedBatch {
+    batch_started_at: DateTime<Utc>,
+    queue_drained: bool,
+    realm_ids: Vec<Key>,
+    realm_failed: Vec<Key>,
+    realm_outcome: UpdateOutcome,
+    realm_meili_duration: Duration,
+    event_ids: Vec<Key>,
+    event_failed: Vec<Key>,
+    event_outcome: UpdateOutcome,
+    event_meili_duration: Duration,
+}
+
+/// Records retry/dead-letter state and task history, then deletes confirmed
+/// items from `search_index_queue`, all inside one write-locked
+/// transaction. Returns whether the queue was fully drained by this pass.
+async fn finalize_batch(db: &mut DbConnection, meili: &Client, batch: FinalizedBatch) -> Result<bool> {
+    writer::with_write_lock(db, meili, move |tx, _meili| Box::pin(async move {
+        // Items that still failed even in isolation get their retry
+        // counter bumped (with exponential backoff) or get dead-lettered
+        // once they have exhausted their attempts.
+        if !batch.realm_failed.is_empty() {
+            record_failures(tx, "realm", &batch.realm_failed).await?;
+        }
+        if !batch.event_failed.is_empty() {
+            record_failures(tx, "event", &batch.event_failed).await?;
+        }
+
+        // A kind that had nothing queued this pass gets no history row at
+        // all, instead of a misleading all-zeroes entry.
+        let realm_has_work = !batch.realm_ids.is_empty() || !batch.realm_failed.is_empty();
+        let event_has_work = !batch.event_ids.is_empty() || !batch.event_failed.is_empty();
+        let finished_at = Utc::now();
+        if realm_has_work {
+            let (added, deleted) = confirmed_counts(&batch.realm_outcome, &batch.realm_failed);
+            record_task_history(tx, TaskHistoryEntry {
+                kind: "realm",
+                started_at: batch.batch_started_at,
+                finished_at,
+                db_load_duration: batch.realm_outcome.load_duration,
+                meili_duration: batch.realm_meili_duration,
+                added,
+                deleted,
+                error: (!batch.realm_failed.is_empty())
+                    .then(|| format!("{} realm(s) failed to index", batch.realm_failed.len())),
+            }).await?;
+        }
+        if event_has_work {
+            let (added, deleted) = confirmed_counts(&batch.event_outcome, &batch.event_failed);
+            record_task_history(tx, TaskHistoryEntry {
+                kind: "event",
+                started_at: batch.batch_started_at,
+                finished_at,
+                db_load_duration: batch.event_outcome.load_duration,
+                meili_duration: batch.event_meili_duration,
+                added,
+                deleted,
+                error: (!batch.event_failed.is_empty())
+                    .then(|| format!("{} event(s) failed to index", batch.event_failed.len())),
+            }).await?;
+        }
+
+        let confirmed_count = batch.realm_ids.len() + batch.event_ids.len();
+        if confirmed_count == 0 {
+            return Ok(batch.queue_drained);
+        }
+
+        // Delete all items that we have confirmed were successfully sent
+        // to the search index. Items whose tasks failed above stay in
+        // `search_index_queue` (unless they were just dead-lettered) and
+        // will be retried once their `next_attempt_at` has passed.
+        let sql = "delete from search_index_queue \
+            where item_id = any($1) and kind = 'realm' \
+            or item_id = any($2) and kind = 'event'";
+        let affected = tx.execute(sql, &[&batch.realm_ids, &batch.event_ids]).await
+            .context("failed to remove items from search index queue")?;
+        debug!("Removed {affected} items from the search index queue");
+
+        if affected != confirmed_count as u64 {
+            warn!("Wanted to delete {confirmed_count} items from search index queue, \
+                but deleted {affected}");
         }
+
+        Ok(batch.queue_drained)
+    })).await
+}
+
+/// Checks whether `search_index_queue` has any rows eligible to be picked up
+/// right now, i.e. the same predicate the batch selection itself uses.
+async fn queue_is_empty(db: &mut DbConnection) -> Result<bool> {
+    let row = db.query_one(
+        "select not exists(
+            select 1 from search_index_queue where next_attempt_at is null or next_attempt_at <= now()
+        ) as empty",
+        &[],
+    ).await.context("failed to check whether search index queue is empty")?;
+    Ok(row.get("empty"))
+}
+
+/// Counts how many of `outcome`'s added/deleted IDs are *not* in `failed`,
+/// i.e. were actually confirmed as indexed rather than merely attempted.
+fn confirmed_counts(outcome: &UpdateOutcome, failed: &[Key]) -> (usize, usize) {
+    let failed: HashSet<Key> = failed.iter().copied().collect();
+    let added = outcome.added_ids.iter().filter(|id| !failed.contains(id)).count();
+    let deleted = outcome.deleted_ids.iter().filter(|id| !failed.contains(id)).count();
+    (added, deleted)
+}
+
+/// Trims `realm_ids`/`event_ids` to `limit` combined, always keeping at
+/// least one item. Anything trimmed off is left for the next batch.
+fn cap_to_document_budget(realm_ids: &mut Vec<Key>, event_ids: &mut Vec<Key>, limit: u32) {
+    let limit = (limit as usize).max(1);
+    if realm_ids.len() + event_ids.len() <= limit {
+        return;
+    }
+
+    if realm_ids.len() >= limit {
+        realm_ids.truncate(limit);
+        event_ids.clear();
+    } else {
+        event_ids.truncate(limit - realm_ids.len());
     }
+}
+
+/// One row to be written to `search_index_tasks`, recording what happened
+/// while indexing one kind (realm/event) of one batch. Analogous to Meili's
+/// own `UpdateResult`.
+struct TaskHistoryEntry {
+    kind: &'static str,
+    started_at: DateTime<Utc>,
+    finished_at: DateTime<Utc>,
+    db_load_duration: Duration,
+    meili_duration: Duration,
+    added: usize,
+    deleted: usize,
+    error: Option<String>,
+}
+
+/// Inserts one `TaskHistoryEntry` into `search_index_tasks`.
+async fn record_task_history(tx: &impl GenericClient, entry: TaskHistoryEntry) -> Result<()> {
+    let sql = "insert into search_index_tasks \
+        (kind, started_at, finished_at, db_load_duration_ms, meili_duration_ms, \
+            added_count, deleted_count, error) \
+        values ($1, $2, $3, $4, $5, $6, $7, $8)";
+    tx.execute(sql, &[
+        &entry.kind,
+        &entry.started_at,
+        &entry.finished_at,
+        &(entry.db_load_duration.as_millis() as i64),
+        &(entry.meili_duration.as_millis() as i64),
+        &(entry.added as i64),
+        &(entry.deleted as i64),
+        &entry.error,
+    ]).await.context("failed to record search index task history")?;
 
     Ok(())
 }
 
+/// A row from `search_index_tasks`, as exposed by the `searchIndexTasks`
+/// GraphQL field.
+pub(crate) struct IndexTaskRecord {
+    pub(crate) id: i64,
+    pub(crate) kind: String,
+    pub(crate) started_at: DateTime<Utc>,
+    pub(crate) finished_at: DateTime<Utc>,
+    pub(crate) db_load_duration_ms: i64,
+    pub(crate) meili_duration_ms: i64,
+    pub(crate) added_count: i64,
+    pub(crate) deleted_count: i64,
+    pub(crate) error: Option<String>,
+}
+
+/// Returns the `limit` most recent entries from `search_index_tasks`, newest
+/// first.
+pub(crate) async fn recent_tasks(db: &mut DbConnection, limit: i64) -> Result<Vec<IndexTaskRecord>> {
+    let rows = db.query(
+        "select id, kind, started_at, finished_at, db_load_duration_ms, \
+            meili_duration_ms, added_count, deleted_count, error \
+            from search_index_tasks \
+            order by id desc \
+            limit $1",
+        &[&limit],
+    ).await.context("failed to load search index task history")?;
+
+    Ok(rows.iter().map(|row| IndexTaskRecord {
+        id: row.get("id"),
+        kind: row.get("kind"),
+        started_at: row.get("started_at"),
+        finished_at: row.get("finished_at"),
+        db_load_duration_ms: row.get("db_load_duration_ms"),
+        meili_duration_ms: row.get("meili_duration_ms"),
+        added_count: row.get("added_count"),
+        deleted_count: row.get("deleted_count"),
+        error: row.get("error"),
+    }).collect())
+}
+
+/// Records that the given `ids` (of the given `kind`, either `"realm"` or
+/// `"event"`) failed to index even when retried individually. Bumps their
+/// retry counter with exponential backoff via `next_attempt_at`, or, once an
+/// item has exhausted `MAX_RETRY_ATTEMPTS`, moves it to
+/// `search_index_dead_letter`. The error stored in `last_error` is always the
+/// same generic message; Meili's own error for the task is not recorded.
+async fn record_failures(tx: &impl GenericClient, kind: &str, ids: &[Key]) -> Result<()> {
+    const ERROR_MESSAGE: &str = "Meili indexing failed";
+
+    let move_to_dead_letter_sql = format!(
+        "insert into search_index_dead_letter (item_id, kind, retry_count, last_error) \
+            select item_id, kind, retry_count + 1, $2 \
+            from search_index_queue \
+            where item_id = any($1) and kind = '{kind}' and retry_count + 1 >= $3");
+    tx.execute(&move_to_dead_letter_sql, &[&ids, &ERROR_MESSAGE, &MAX_RETRY_ATTEMPTS]).await
+        .context("failed to move search index queue items to dead letter table")?;
+
+    let delete_dead_letter_sql = format!(
+        "delete from search_index_queue \
+            where item_id = any($1) and kind = '{kind}' and retry_count + 1 >= $2");
+    tx.execute(&delete_dead_letter_sql, &[&ids, &MAX_RETRY_ATTEMPTS]).await
+        .context("failed to remove dead-lettered items from search index queue")?;
+
+    let bump_retry_sql = format!(
+        "update search_index_queue \
+            set retry_count = retry_count + 1, \
+                last_error = $2, \
+                next_attempt_at = now() + (least(power(2, retry_count + 1)::int, 3600) * interval '1 second') \
+            where item_id = any($1) and kind = '{kind}' and retry_count + 1 < $3");
+    tx.execute(&bump_retry_sql, &[&ids, &ERROR_MESSAGE, &MAX_RETRY_ATTEMPTS]).await
+        .context("failed to update retry state for failed search index queue items")?;
+
+    warn!(
+        "{} {kind}(s) failed to index even in isolation; bumped retry counters \
+            (dead-lettering any that reached {MAX_RETRY_ATTEMPTS} attempts)",
+        ids.len(),
+    );
+
+    Ok(())
+}
+
+/// Outcome of one `MeiliWriter::update` call: the Meili tasks it started,
+/// the IDs it added/deleted (so a caller can later work out how many were
+/// *confirmed*), and how long loading them from the DB took.
+#[derive(Default)]
+pub(crate) struct UpdateOutcome {
+    pub(crate) tasks: Vec<TaskInfo>,
+    pub(crate) added_ids: Vec<Key>,
+    pub(crate) deleted_ids: Vec<Key>,
+    pub(crate) load_duration: Duration,
+}
+
 impl MeiliWriter<'_> {
     /// Loads items from the DB with the given loader and then adds them to
     /// Meili. All items that were not returned by `loader` but are present in
-    /// `ids` are deleted from the index.
-    pub(crate) async fn update<L, F, T>(&self, ids: &[Key], loader: L) -> Result<()>
+    /// `ids` are deleted from the index. Returns the Meili tasks that were
+    /// started for this, plus how many documents were added/deleted, so the
+    /// caller can wait for the tasks to complete and record this batch in
+    /// `search_index_tasks`.
+    pub(crate) async fn update<L, F, T>(&self, ids: &[Key], loader: L) -> Result<UpdateOutcome>
     where
         L: FnOnce() -> F,
         F: Future<Output = Result<Vec<T>>>,
@@ -112,11 +469,13 @@ impl MeiliWriter<'_> {
 
         if ids.is_empty() {
             trace!("No {} in need of a search index update", kind.plural_name());
-            return Ok(());
+            return Ok(UpdateOutcome::default());
         }
 
         // Load all new items from the DB.
+        let load_started_at = Instant::now();
         let items = loader().await?;
+        let load_duration = load_started_at.elapsed();
         debug!(
             "Loaded {} {} from DB to be added to search index",
             items.len(),
@@ -125,11 +484,8 @@ impl MeiliWriter<'_> {
 
         // Figure out which ones were deleted
         let existing_item_ids = items.iter().map(|r| r.id().0).collect::<HashSet<_>>();
-        let deleted_items = ids.iter()
-            .copied()
-            .filter(|id| !existing_item_ids.contains(id))
-            .map(SearchId)
-            .collect::<Vec<_>>();
+        let added_ids = items.iter().map(|r| r.id().0).collect::<Vec<_>>();
+        let deleted_ids = ids.iter().copied().filter(|id| !existing_item_ids.contains(id)).collect::<Vec<_>>();
 
         // Obtain the correct index.
         let index = match kind {
@@ -137,17 +493,56 @@ impl MeiliWriter<'_> {
             IndexItemKind::Event => &self.event_index,
         };
 
-        // Actually update documents in Meili.
-        if !deleted_items.is_empty() {
-            index.delete_documents(&deleted_items).await?;
-            debug!("Started deletion of {} {} in Meili", deleted_items.len(), kind.plural_name());
+        // Actually update documents in Meili. Both calls only enqueue a task
+        // and return its UID; the caller decides whether and how to wait for
+        // it to finish.
+        let mut tasks = Vec::new();
+
+        if !deleted_ids.is_empty() {
+            let search_ids = deleted_ids.iter().copied().map(SearchId).collect::<Vec<_>>();
+            let task = index.delete_documents(&search_ids).await?;
+            debug!("Started deletion of {} {} in Meili", deleted_ids.len(), kind.plural_name());
+            tasks.push(task);
         }
 
         if !items.is_empty() {
-            index.add_documents(&items, None).await?;
+            let task = index.add_documents(&items, None).await?;
             debug!("Sent {} {} to Meili for indexing", items.len(), kind.plural_name());
+            tasks.push(task);
+        }
+
+        Ok(UpdateOutcome { tasks, added_ids, deleted_ids, load_duration })
+    }
+
+    /// Waits for all given Meili tasks to reach a terminal state (`succeeded`
+    /// or `failed`), polling at the fixed `TASK_POLL_INTERVAL`; the interval
+    /// does not grow between polls. Returns `true` only if
+    /// every task reached `succeeded`; any failure, or a task that is still
+    /// not terminal once `TASK_POLL_TIMEOUT` elapses, counts as not
+    /// succeeded.
+    async fn wait_for_tasks(&self, tasks: Vec<TaskInfo>) -> bool {
+        let mut all_succeeded = true;
+        for task in tasks {
+            let uid = task.get_task_uid();
+            let status = task.wait_for_completion(
+                self.client,
+                Some(TASK_POLL_INTERVAL),
+                Some(TASK_POLL_TIMEOUT),
+            ).await;
+
+            match status {
+                Ok(Task::Succeeded { .. }) => {}
+                Ok(other) => {
+                    warn!("Meili task {uid} did not succeed: {other:?}");
+                    all_succeeded = false;
+                }
+                Err(e) => {
+                    warn!("Failed to check status of Meili task {uid}: {e}");
+                    all_succeeded = false;
+                }
+            }
         }
 
-        Ok(())
+        all_succeeded
     }
 }